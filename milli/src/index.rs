@@ -21,7 +21,7 @@ use crate::heed_codec::{
     BEU16StrCodec, FstSetCodec, ScriptLanguageCodec, StrBEU16Codec, StrRefCodec,
 };
 use crate::order_by_map::OrderByMap;
-use crate::proximity::ProximityPrecision;
+use crate::proximity::{ProximityPrecision, MAX_DISTANCE};
 use crate::vector::EmbeddingConfig;
 use crate::{
     default_criteria, CboRoaringBitmapCodec, Criterion, DocumentId, ExternalDocumentsIds,
@@ -1145,6 +1145,33 @@ impl Index {
         self.word_docids.remap_data_type::<RoaringBitmapLenCodec>().get(rtxn, word)
     }
 
+    /* word pair proximity docids */
+
+    /// Returns, for each of the given word pairs, whether the `word_pair_proximity_docids`
+    /// database contains an entry for it at any proximity, without decoding the bitmaps.
+    ///
+    /// Only the `(word1, word2)` order is checked, and the database is only filled when
+    /// the proximity precision is [`ProximityPrecision::ByWord`].
+    pub fn pairs_with_proximity_data(
+        &self,
+        rtxn: &RoTxn,
+        pairs: &[(&str, &str)],
+    ) -> heed::Result<Vec<bool>> {
+        let db = self.word_pair_proximity_docids.remap_data_type::<DecodeIgnore>();
+        let mut found = Vec::with_capacity(pairs.len());
+        for &(word1, word2) in pairs {
+            let mut exists = false;
+            for proximity in 1..MAX_DISTANCE as u8 {
+                if db.get(rtxn, &(proximity, word1, word2))?.is_some() {
+                    exists = true;
+                    break;
+                }
+            }
+            found.push(exists);
+        }
+        Ok(found)
+    }
+
     /* documents */
 
     /// Returns an iterator over the requested documents. The next item will be an error if a document is missing.
@@ -1784,6 +1811,31 @@ pub(crate) mod tests {
         assert_eq!(index.min_word_len_two_typos(&txn).unwrap(), 15);
     }
 
+    #[test]
+    fn pairs_with_proximity_data() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 1, "text": "the quick brown fox" },
+                { "id": 2, "text": "a lazy dog" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let pairs = [
+            ("quick", "brown"),
+            ("quick", "fox"),
+            ("the", "fox"),
+            ("quick", "dog"),
+            ("lazy", "dog"),
+            ("kevin", "fox"),
+        ];
+        let found = index.pairs_with_proximity_data(&rtxn, &pairs).unwrap();
+        assert_eq!(found, vec![true, true, true, false, true, false]);
+
+        assert!(index.pairs_with_proximity_data(&rtxn, &[]).unwrap().is_empty());
+    }
+
     #[test]
     fn add_documents_and_set_searchable_fields() {
         let index = TempIndex::new();